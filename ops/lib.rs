@@ -44,6 +44,8 @@ fn core_import() -> TokenStream2 {
 struct MacroArgs {
   is_unstable: bool,
   is_v8: bool,
+  is_stream: bool,
+  is_fast: bool,
 }
 
 impl syn::parse::Parse for MacroArgs {
@@ -55,16 +57,18 @@ impl syn::parse::Parse for MacroArgs {
     let vars: Vec<_> = vars.iter().map(Ident::to_string).collect();
     let vars: Vec<_> = vars.iter().map(String::as_str).collect();
     for var in vars.iter() {
-      if !["unstable", "v8"].contains(var) {
+      if !["unstable", "v8", "stream", "fast"].contains(var) {
         return Err(syn::Error::new(
           input.span(),
-          "Ops expect #[op] or #[op(unstable)]",
+          "Ops expect #[op], #[op(unstable)], #[op(v8)], #[op(stream)] or #[op(fast)]",
         ));
       }
     }
     Ok(Self {
       is_unstable: vars.contains(&"unstable"),
       is_v8: vars.contains(&"v8"),
+      is_stream: vars.contains(&"stream"),
+      is_fast: vars.contains(&"fast"),
     })
   }
 }
@@ -72,8 +76,20 @@ impl syn::parse::Parse for MacroArgs {
 #[proc_macro_attribute]
 pub fn op(attr: TokenStream, item: TokenStream) -> TokenStream {
   let margs = syn::parse_macro_input!(attr as MacroArgs);
-  let MacroArgs { is_unstable, is_v8 } = margs;
-  let func = syn::parse::<syn::ItemFn>(item).expect("expected a function");
+  let MacroArgs {
+    is_unstable, is_v8, ..
+  } = margs;
+  let func = match syn::parse::<syn::ItemFn>(item) {
+    Ok(func) => func,
+    Err(err) => {
+      return syn::Error::new(
+        err.span(),
+        "#[op] requires a function as input",
+      )
+      .to_compile_error()
+      .into()
+    }
+  };
   let name = &func.sig.ident;
   let mut generics = func.sig.generics.clone();
   let scope_lifetime =
@@ -96,16 +112,28 @@ pub fn op(attr: TokenStream, item: TokenStream) -> TokenStream {
   let core = core_import();
 
   let asyncness = func.sig.asyncness.is_some();
-  let is_async = asyncness || is_future(&func.sig.output);
-  let v8_body = if is_async {
+  let is_stream = margs.is_stream || is_stream_type(&func.sig.output);
+  let is_async = asyncness || is_future(&func.sig.output) || is_stream;
+  let v8_body = if is_stream {
+    codegen_v8_stream(&core, &func, margs)
+  } else if is_async {
     codegen_v8_async(&core, &func, margs, asyncness)
   } else {
     codegen_v8_sync(&core, &func, margs)
   };
+  let v8_body = match v8_body {
+    Ok(body) => body,
+    Err(err) => return err.to_compile_error().into(),
+  };
 
   let docline = format!("Use `{name}::decl()` to get an op-declaration");
+  let ambiguous_result_hint = ambiguous_result_hint(name, &func.sig.output);
+  let (fast_fn_decl, fast_fn_impl) =
+    codegen_fast(&core, &func, margs, where_clause);
   // Generate wrapper
   quote! {
+    #ambiguous_result_hint
+
     #[allow(non_camel_case_types)]
     #[doc="Auto-generated by `deno_ops`, i.e: `#[op]`"]
     #[doc=""]
@@ -132,6 +160,7 @@ pub fn op(attr: TokenStream, item: TokenStream) -> TokenStream {
           is_async: #is_async,
           is_unstable: #is_unstable,
           is_v8: #is_v8,
+          fast_fn: #fast_fn_decl,
         }
       }
 
@@ -146,6 +175,8 @@ pub fn op(attr: TokenStream, item: TokenStream) -> TokenStream {
       ) #where_clause {
         #v8_body
       }
+
+      #fast_fn_impl
     }
   }.into()
 }
@@ -156,7 +187,7 @@ fn codegen_v8_async(
   f: &syn::ItemFn,
   margs: MacroArgs,
   asyncness: bool,
-) -> TokenStream2 {
+) -> Result<TokenStream2, syn::Error> {
   let MacroArgs { is_v8, .. } = margs;
   let special_args = f
     .sig
@@ -169,7 +200,7 @@ fn codegen_v8_async(
   let rust_i0 = special_args.len();
   let args_head = special_args.into_iter().collect::<TokenStream2>();
 
-  let (arg_decls, args_tail) = codegen_args(core, f, rust_i0, 1);
+  let (arg_decls, args_tail) = codegen_args(core, f, margs, rust_i0, 1)?;
   let type_params = exclude_lifetime_params(&f.sig.generics.params);
 
   let (pre_result, mut result_fut) = match asyncness {
@@ -200,7 +231,7 @@ fn codegen_v8_async(
     false => quote! { let result = Ok(result); },
   };
 
-  quote! {
+  Ok(quote! {
     use #core::futures::FutureExt;
     // SAFETY: #core guarantees args.data() is a v8 External pointing to an OpCtx for the isolates lifetime
     let ctx = unsafe {
@@ -239,7 +270,7 @@ fn codegen_v8_async(
       #result_wrapper
       (promise_id, op_id, #core::_ops::to_op_result(get_class, result))
     });
-  }
+  })
 }
 
 fn scope_arg(arg: &FnArg) -> Option<TokenStream2> {
@@ -260,12 +291,88 @@ fn opstate_arg(arg: &FnArg) -> Option<TokenStream2> {
   }
 }
 
+/// Generate the body of a v8 func for a streaming op, i.e. one returning
+/// `impl Stream<Item = T>` (or flagged `#[op(stream)]`).
+fn codegen_v8_stream(
+  core: &TokenStream2,
+  f: &syn::ItemFn,
+  margs: MacroArgs,
+) -> Result<TokenStream2, syn::Error> {
+  if let Some(asyncness) = f.sig.asyncness {
+    return Err(syn::Error::new_spanned(
+      asyncness,
+      "#[op(stream)] functions must not be `async`; return \
+       `impl Stream<Item = T>` directly from a sync function",
+    ));
+  }
+  let MacroArgs { is_v8, .. } = margs;
+  let special_args = f
+    .sig
+    .inputs
+    .iter()
+    .map_while(|a| {
+      (if is_v8 { scope_arg(a) } else { None }).or_else(|| opstate_arg(a))
+    })
+    .collect::<Vec<_>>();
+  let rust_i0 = special_args.len();
+  let args_head = special_args.into_iter().collect::<TokenStream2>();
+
+  let (arg_decls, args_tail) = codegen_args(core, f, margs, rust_i0, 1)?;
+  let type_params = exclude_lifetime_params(&f.sig.generics.params);
+
+  Ok(quote! {
+    use #core::futures::StreamExt;
+    // SAFETY: #core guarantees args.data() is a v8 External pointing to an OpCtx for the isolates lifetime
+    let ctx = unsafe {
+      &*(#core::v8::Local::<#core::v8::External>::cast(args.data().unwrap_unchecked()).value()
+      as *const #core::_ops::OpCtx)
+    };
+    let op_id = ctx.id;
+
+    let promise_id = args.get(0);
+    let promise_id = #core::v8::Local::<#core::v8::Integer>::try_from(promise_id)
+      .map(|l| l.value() as #core::PromiseId)
+      .map_err(#core::anyhow::Error::from);
+    // Fail if promise id invalid (not an int)
+    let promise_id: #core::PromiseId = match promise_id {
+      Ok(promise_id) => promise_id,
+      Err(err) => {
+        #core::_ops::throw_type_error(scope, format!("invalid promise id: {}", err));
+        return;
+      }
+    };
+
+    #arg_decls
+
+    let state = ctx.state.clone();
+
+    // Track async call & get copy of get_error_class_fn
+    let get_class = {
+      let state = state.borrow();
+      state.tracker.track_async(op_id);
+      state.get_error_class_fn
+    };
+
+    let stream = Self::call::<#type_params>(#args_head #args_tail).boxed_local();
+    #core::_ops::queue_async_op(scope, async move {
+      let mut stream = stream;
+      while let Some(item) = stream.next().await {
+        #core::_ops::send_op_result(promise_id, op_id, #core::_ops::to_op_result(get_class, Ok(item)));
+      }
+      // The final tuple is the "done" sentinel: an ordinary unit result,
+      // distinguished from a chunk only by being the future's return value
+      // rather than an intermediate `send_op_result` call.
+      (promise_id, op_id, #core::_ops::to_op_result::<()>(get_class, Ok(())))
+    });
+  })
+}
+
 /// Generate the body of a v8 func for a sync op
 fn codegen_v8_sync(
   core: &TokenStream2,
   f: &syn::ItemFn,
   margs: MacroArgs,
-) -> TokenStream2 {
+) -> Result<TokenStream2, syn::Error> {
   let MacroArgs { is_v8, .. } = margs;
   let special_args = f
     .sig
@@ -278,11 +385,11 @@ fn codegen_v8_sync(
   let rust_i0 = special_args.len();
   let args_head = special_args.into_iter().collect::<TokenStream2>();
 
-  let (arg_decls, args_tail) = codegen_args(core, f, rust_i0, 0);
+  let (arg_decls, args_tail) = codegen_args(core, f, margs, rust_i0, 0)?;
   let ret = codegen_sync_ret(core, &f.sig.output);
   let type_params = exclude_lifetime_params(&f.sig.generics.params);
 
-  quote! {
+  Ok(quote! {
     // SAFETY: #core guarantees args.data() is a v8 External pointing to an OpCtx for the isolates lifetime
     let ctx = unsafe {
       &*(#core::v8::Local::<#core::v8::External>::cast(args.data().unwrap_unchecked()).value()
@@ -297,49 +404,62 @@ fn codegen_v8_sync(
     op_state.tracker.track_sync(ctx.id);
 
     #ret
-  }
+  })
 }
 
 fn codegen_args(
   core: &TokenStream2,
   f: &syn::ItemFn,
+  margs: MacroArgs,
   rust_i0: usize, // Index of first generic arg in rust
   v8_i0: usize,   // Index of first generic arg in v8/js
-) -> (TokenStream2, TokenStream2) {
+) -> Result<(TokenStream2, TokenStream2), syn::Error> {
   let inputs = &f.sig.inputs.iter().skip(rust_i0).enumerate();
-  let ident_seq: TokenStream2 = inputs
+  let idents: Vec<Ident> = inputs
     .clone()
-    .map(|(i, _)| format!("arg_{i}"))
-    .collect::<Vec<_>>()
-    .join(", ")
-    .parse()
-    .unwrap();
+    .map(|(i, _)| quote::format_ident!("arg_{i}"))
+    .collect();
+  let ident_seq: TokenStream2 = quote! { #(#idents),* };
   let decls: TokenStream2 = inputs
     .clone()
     .map(|(i, arg)| {
-      codegen_arg(core, arg, format!("arg_{i}").as_ref(), v8_i0 + i)
+      codegen_arg(core, arg, margs, format!("arg_{i}").as_ref(), v8_i0 + i)
     })
-    .collect();
-  (decls, ident_seq)
+    .collect::<Result<_, _>>()?;
+  Ok((decls, ident_seq))
 }
 
 fn codegen_arg(
   core: &TokenStream2,
   arg: &syn::FnArg,
+  margs: MacroArgs,
   name: &str,
   idx: usize,
-) -> TokenStream2 {
+) -> Result<TokenStream2, syn::Error> {
   let ident = quote::format_ident!("{name}");
   let pat = match arg {
     syn::FnArg::Typed(pat) => &pat.pat,
-    _ => unreachable!(),
+    syn::FnArg::Receiver(receiver) => {
+      return Err(syn::Error::new_spanned(
+        receiver,
+        "#[op] functions cannot have a `self` receiver",
+      ))
+    }
   };
   // Fast path if arg should be skipped
   if matches!(**pat, syn::Pat::Wild(_)) {
-    return quote! { let #ident = (); };
+    return Ok(quote! { let #ident = (); });
+  }
+  // Fast path for primitive numeric/bool args: skip the serde_v8 round-trip.
+  // Only for #[op(fast)], since it changes argument validation (e.g. range
+  // checks instead of serde_v8's own coercion/BigInt rules).
+  if margs.is_fast {
+    if let Some(ty) = fast_primitive_arg_ty(arg) {
+      return Ok(codegen_arg_primitive(core, &ident, idx, &ty));
+    }
   }
   // Otherwise deserialize it via serde_v8
-  quote! {
+  Ok(quote! {
     let #ident = args.get(#idx as i32);
     let #ident = match #core::serde_v8::from_v8(scope, #ident) {
       Ok(v) => v,
@@ -348,6 +468,60 @@ fn codegen_arg(
         return #core::_ops::throw_type_error(scope, msg);
       }
     };
+  })
+}
+
+/// Direct `v8::Local<Number/Boolean>` conversion for a primitive-typed
+/// argument, bypassing the `serde_v8::from_v8` round-trip. Integer types
+/// get an explicit range/integrality check instead of a saturating `as`
+/// cast, so out-of-range or fractional JS numbers throw a `TypeError`
+/// rather than silently saturating or truncating.
+fn codegen_arg_primitive(
+  core: &TokenStream2,
+  ident: &Ident,
+  idx: usize,
+  ty: &Ident,
+) -> TokenStream2 {
+  if ty == "bool" {
+    quote! {
+      let #ident = args.get(#idx as i32);
+      let #ident = match #core::v8::Local::<#core::v8::Boolean>::try_from(#ident) {
+        Ok(v) => v.is_true(),
+        Err(_) => {
+          let msg = format!("Error parsing args at position {}: expected a boolean", #idx);
+          return #core::_ops::throw_type_error(scope, msg);
+        }
+      };
+    }
+  } else if ty == "f32" || ty == "f64" {
+    quote! {
+      let #ident = args.get(#idx as i32);
+      let #ident = match #core::v8::Local::<#core::v8::Number>::try_from(#ident) {
+        Ok(v) => v.value() as #ty,
+        Err(_) => {
+          let msg = format!("Error parsing args at position {}: expected a number", #idx);
+          return #core::_ops::throw_type_error(scope, msg);
+        }
+      };
+    }
+  } else {
+    quote! {
+      let #ident = args.get(#idx as i32);
+      let #ident = match #core::v8::Local::<#core::v8::Number>::try_from(#ident) {
+        Ok(v) => {
+          let n = v.value();
+          if !n.is_finite() || n.fract() != 0.0 || n < #ty::MIN as f64 || n >= #ty::MAX as f64 + 1.0 {
+            let msg = format!("Error parsing args at position {}: expected an integer in range for `{}`", #idx, stringify!(#ty));
+            return #core::_ops::throw_type_error(scope, msg);
+          }
+          n as #ty
+        }
+        Err(_) => {
+          let msg = format!("Error parsing args at position {}: expected a number", #idx);
+          return #core::_ops::throw_type_error(scope, msg);
+        }
+      };
+    }
   }
 }
 
@@ -391,6 +565,102 @@ fn codegen_sync_ret(
   }
 }
 
+/// Whether `f` is eligible for a V8 fast-API callback: sync, non-generic,
+/// and every argument and the return type are fast-compatible primitives.
+fn fast_api_eligible(f: &syn::ItemFn) -> bool {
+  if f.sig.asyncness.is_some()
+    || is_future(&f.sig.output)
+    || is_stream_type(&f.sig.output)
+  {
+    return false;
+  }
+  if !exclude_lifetime_params(&f.sig.generics.params).is_empty() {
+    return false;
+  }
+  if f.sig.inputs.iter().any(|a| fast_primitive_arg_ty(a).is_none()) {
+    return false;
+  }
+  is_void(&f.sig.output) || fast_primitive_return_ty(&f.sig.output).is_some()
+}
+
+fn fast_api_type(core: &TokenStream2, ty: &Ident) -> TokenStream2 {
+  let variant = match ty.to_string().as_str() {
+    "bool" => quote! { Bool },
+    "u8" | "u16" | "u32" => quote! { Uint32 },
+    "i8" | "i16" | "i32" => quote! { Int32 },
+    "u64" | "usize" => quote! { Uint64 },
+    "i64" | "isize" => quote! { Int64 },
+    "f32" => quote! { Float32 },
+    "f64" => quote! { Float64 },
+    _ => unreachable!(),
+  };
+  quote! { #core::v8::fast_api::Type::#variant }
+}
+
+/// Generates the `Self::v8_fast_fn()` call for `OpDecl.fast_fn`, plus the
+/// `v8_fast_fn`/`v8_fast_func` methods backing it, mirroring how `decl()`
+/// calls `Self::v8_fn_ptr()`. Returns `(quote!{ None }, quote!{})` unless
+/// `margs.is_fast` and `fast_api_eligible(f)` both hold.
+fn codegen_fast(
+  core: &TokenStream2,
+  f: &syn::ItemFn,
+  margs: MacroArgs,
+  where_clause: &Option<syn::WhereClause>,
+) -> (TokenStream2, TokenStream2) {
+  if !margs.is_fast || !fast_api_eligible(f) {
+    return (quote! { None }, quote! {});
+  }
+
+  let arg_types: Vec<TokenStream2> = f
+    .sig
+    .inputs
+    .iter()
+    .map(|a| fast_api_type(core, &fast_primitive_arg_ty(a).unwrap()))
+    .collect();
+  let fast_params: Vec<TokenStream2> = f
+    .sig
+    .inputs
+    .iter()
+    .enumerate()
+    .map(|(i, a)| {
+      let ident = quote::format_ident!("arg_{i}");
+      let ty = fast_primitive_arg_ty(a).unwrap();
+      quote! { #ident: #ty }
+    })
+    .collect();
+  let arg_idents: Vec<TokenStream2> = (0..f.sig.inputs.len())
+    .map(|i| {
+      let ident = quote::format_ident!("arg_{i}");
+      quote! { #ident }
+    })
+    .collect();
+  let (ret_ty, ret_api_ty) = match fast_primitive_return_ty(&f.sig.output) {
+    Some(ty) => (quote! { #ty }, fast_api_type(core, &ty)),
+    None => (quote! { () }, quote! { #core::v8::fast_api::Type::Void }),
+  };
+
+  let call = quote! { Self::v8_fast_fn() };
+  let imp = quote! {
+    #[doc(hidden)]
+    pub extern "C" fn v8_fast_func(
+      _recv: #core::v8::Local<#core::v8::Object>,
+      #(#fast_params),*
+    ) -> #ret_ty #where_clause {
+      Self::call(#(#arg_idents),*)
+    }
+
+    #[doc(hidden)]
+    pub fn v8_fast_fn() -> Option<#core::FastFunction> #where_clause {
+      Some(#core::FastFunction::new(
+        &[#core::v8::fast_api::Type::V8Value, #(#arg_types),*],
+        #ret_api_ty,
+        Self::v8_fast_func as *const ::std::ffi::c_void,
+      ))
+    }
+  };
+  (call, imp)
+}
+
 fn is_void(ty: impl ToTokens) -> bool {
   tokens(ty).is_empty()
 }
@@ -413,6 +683,52 @@ fn is_unit_result(ty: impl ToTokens) -> bool {
   is_result(&ty) && tokens(&ty).contains("Result < ()")
 }
 
+/// Emits a deprecation-warning hint when an op's return type is a generic
+/// whose name looks like a `Result` alias (e.g. `IoResult`, `JsResult`)
+/// that `is_result` can't disambiguate from an arbitrary plain value.
+fn ambiguous_result_hint(
+  op_name: &Ident,
+  output: &syn::ReturnType,
+) -> TokenStream2 {
+  let ty = match output {
+    syn::ReturnType::Type(_, ty) => ty,
+    syn::ReturnType::Default => return quote! {},
+  };
+  let segment = match &**ty {
+    syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last(),
+    _ => None,
+  };
+  let segment = match segment {
+    Some(segment) => segment,
+    None => return quote! {},
+  };
+  let is_generic =
+    matches!(segment.arguments, syn::PathArguments::AngleBracketed(_));
+  let name = segment.ident.to_string();
+  let looks_like_result =
+    name != "Result" && name.to_ascii_lowercase().contains("result");
+  if !is_generic || !looks_like_result {
+    return quote! {};
+  }
+  let msg = format!(
+    "op return type `{name}<..>` is ambiguous: #[op] can't tell whether this \
+     is a `Result` alias or a plain value, and will treat it as a plain \
+     value. If `{name}` is a `Result` shorthand, spell the return type as a \
+     fully-qualified `std::result::Result<T, E>` instead.",
+  );
+  let span = segment.ident.span();
+  let const_name = quote::format_ident!(
+    "_{op_name}_ambiguous_result_hint",
+    span = span
+  );
+  quote::quote_spanned! { span =>
+    #[deprecated(note = #msg)]
+    #[allow(non_upper_case_globals)]
+    const #const_name: () = ();
+    const _: () = #const_name;
+  }
+}
+
 fn is_mut_ref_opstate(arg: &syn::FnArg) -> bool {
   static RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#": & mut (?:deno_core :: )?OpState$"#).unwrap());
@@ -438,6 +754,35 @@ fn is_future(ty: impl ToTokens) -> bool {
   tokens(&ty).contains("impl Future < Output =")
 }
 
+fn is_stream_type(ty: impl ToTokens) -> bool {
+  tokens(&ty).contains("impl Stream < Item =")
+}
+
+const PRIMITIVE_TYPES: &str =
+  "u8|u16|u32|u64|usize|i8|i16|i32|i64|isize|f32|f64|bool";
+
+/// Detects an argument typed as one of `PRIMITIVE_TYPES`, returning the
+/// matched type as an `Ident` so it can be spliced back into generated code
+/// (e.g. as an `as` cast target).
+fn fast_primitive_arg_ty(arg: &syn::FnArg) -> Option<Ident> {
+  static RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(r#": ({PRIMITIVE_TYPES})$"#)).unwrap()
+  });
+  let arg = tokens(arg);
+  let caps = RE.captures(&arg)?;
+  Some(Ident::new(&caps[1], Span::call_site()))
+}
+
+/// Same as `fast_primitive_arg_ty`, but for a function's return type.
+fn fast_primitive_return_ty(output: &syn::ReturnType) -> Option<Ident> {
+  static RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(r#"^-> ({PRIMITIVE_TYPES})$"#)).unwrap()
+  });
+  let output = tokens(output);
+  let caps = RE.captures(output.trim())?;
+  Some(Ident::new(&caps[1], Span::call_site()))
+}
+
 fn tokens(x: impl ToTokens) -> String {
   x.to_token_stream().to_string()
 }